@@ -1,8 +1,15 @@
 #![no_std]
 extern crate alloc;
 
-use alloc::vec::Vec;
-use core::{iter::FusedIterator, marker::PhantomData, num::NonZero};
+use alloc::{
+    collections::BinaryHeap,
+    vec::Vec,
+};
+use core::{
+    cmp::Ordering,
+    iter::{FromIterator, FusedIterator},
+    marker::PhantomData,
+};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Deserializer, Serialize};
 
@@ -28,9 +35,37 @@ impl<E> BuildMetric for Levenshtein<E> {
 }
 
 pub trait Metric<A, B> {
-    fn distance(&mut self, a: A, b: B) -> usize;
+    type Dist: Dist;
+
+    fn distance(&mut self, a: A, b: B) -> Self::Dist;
+}
+
+pub trait Dist: Copy + Ord + Default {
+    #[must_use]
+    fn saturating_add(self, other: Self) -> Self;
+
+    #[must_use]
+    fn saturating_sub(self, other: Self) -> Self;
+}
+
+macro_rules! impl_dist {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl Dist for $t {
+                fn saturating_add(self, other: Self) -> Self {
+                    Self::saturating_add(self, other)
+                }
+
+                fn saturating_sub(self, other: Self) -> Self {
+                    Self::saturating_sub(self, other)
+                }
+            }
+        )*
+    };
 }
 
+impl_dist!(usize, u8, u16, u32, u64, u128, isize, i8, i16, i32, i64, i128);
+
 #[derive(Debug)]
 pub struct LevenshteinMetric<E> {
     cache: Vec<usize>,
@@ -38,6 +73,8 @@ pub struct LevenshteinMetric<E> {
 }
 
 impl<A: AsRef<[E]>, B: AsRef<[E]>, E: PartialEq> Metric<A, B> for LevenshteinMetric<E> {
+    type Dist = usize;
+
     fn distance(&mut self, a: A, b: B) -> usize {
         let a = a.as_ref();
         let b = b.as_ref();
@@ -59,27 +96,115 @@ impl<A: AsRef<[E]>, B: AsRef<[E]>, E: PartialEq> Metric<A, B> for LevenshteinMet
     }
 }
 
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DamerauLevenshtein<E>(PhantomData<E>);
+
+impl<E: Ord> BuildMetric for DamerauLevenshtein<E> {
+    type Metric = DamerauLevenshteinMetric<E>;
+
+    fn build(&self) -> Self::Metric {
+        Self::Metric {
+            table: Vec::new(),
+            last_row: Vec::new(),
+        }
+    }
+}
+
+/// Unrestricted (true) Damerau-Levenshtein distance, safe to use as a [`BKMap`] metric.
+#[derive(Debug)]
+pub struct DamerauLevenshteinMetric<E> {
+    table: Vec<usize>,
+    // Kept sorted by symbol and reused across calls; a `BTreeMap` would deallocate its
+    // whole tree on every `clear()`.
+    last_row: Vec<(E, usize)>,
+}
+
+impl<A: AsRef<[E]>, B: AsRef<[E]>, E: Ord + Clone> Metric<A, B> for DamerauLevenshteinMetric<E> {
+    type Dist = usize;
+
+    fn distance(&mut self, a: A, b: B) -> usize {
+        let a = a.as_ref();
+        let b = b.as_ref();
+        let (la, lb) = (a.len(), b.len());
+        let width = lb + 2;
+        let max_dist = la + lb;
+
+        self.table.clear();
+        self.table.resize((la + 2) * width, 0);
+        self.last_row.clear();
+
+        let at = |i: usize, j: usize| i * width + j;
+
+        self.table[at(0, 0)] = max_dist;
+        for i in 0..=la {
+            self.table[at(i + 1, 0)] = max_dist;
+            self.table[at(i + 1, 1)] = i;
+        }
+        for j in 0..=lb {
+            self.table[at(0, j + 1)] = max_dist;
+            self.table[at(1, j + 1)] = j;
+        }
+
+        for (i, a) in a.iter().enumerate() {
+            let i = i + 1;
+            let mut last_col = 0;
+
+            for (j, b) in b.iter().enumerate() {
+                let j = j + 1;
+                let k = self
+                    .last_row
+                    .binary_search_by(|(symbol, _)| symbol.cmp(b))
+                    .map_or(0, |idx| self.last_row[idx].1);
+                let l = last_col;
+
+                let cost = usize::from(a != b);
+                if a == b {
+                    last_col = j;
+                }
+
+                self.table[at(i + 1, j + 1)] = (self.table[at(i, j)] + cost)
+                    .min(self.table[at(i + 1, j)] + 1)
+                    .min(self.table[at(i, j + 1)] + 1)
+                    .min(self.table[at(k, l)] + (i - k - 1) + 1 + (j - l - 1));
+            }
+
+            match self.last_row.binary_search_by(|(symbol, _)| symbol.cmp(a)) {
+                Ok(idx) => self.last_row[idx].1 = i,
+                Err(idx) => self.last_row.insert(idx, (a.clone(), i)),
+            }
+        }
+
+        self.table[at(la + 1, lb + 1)]
+    }
+}
+
 #[cfg_attr(feature = "serde", derive(Serialize))]
 #[derive(Clone, Debug)]
-pub struct BKMap<K, V, M: BuildMetric> {
-    root: Option<BKNode<K, V>>,
+pub struct BKMap<K, V, M: BuildMetric, D = usize> {
+    root: Option<BKNode<K, V, D>>,
     build_metric: M,
     #[cfg_attr(feature = "serde", serde(skip))]
     metric: M::Metric,
 }
 
 #[cfg(feature = "serde")]
-impl<'de, K: Deserialize<'de>, V: Deserialize<'de>, M: Deserialize<'de> + BuildMetric>
-    Deserialize<'de> for BKMap<K, V, M>
+impl<
+        'de,
+        K: Deserialize<'de>,
+        V: Deserialize<'de>,
+        M: Deserialize<'de> + BuildMetric,
+        D: Deserialize<'de>,
+    > Deserialize<'de> for BKMap<K, V, M, D>
 {
-    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    fn deserialize<Dz: Deserializer<'de>>(deserializer: Dz) -> Result<Self, Dz::Error> {
         #[derive(Deserialize)]
-        struct BKMap<K, V, M> {
-            root: Option<BKNode<K, V>>,
+        struct BKMap<K, V, M, D> {
+            root: Option<BKNode<K, V, D>>,
             build_metric: M,
         }
 
-        let BKMap { root, build_metric } = BKMap::<K, V, M>::deserialize(deserializer)?;
+        let BKMap { root, build_metric } = BKMap::<K, V, M, D>::deserialize(deserializer)?;
         let metric = build_metric.build();
 
         Ok(Self {
@@ -92,14 +217,14 @@ impl<'de, K: Deserialize<'de>, V: Deserialize<'de>, M: Deserialize<'de> + BuildM
 
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 #[derive(Clone, Debug)]
-struct BKNode<K, V> {
-    dist: NonZero<usize>,
+struct BKNode<K, V, D> {
+    dist: D,
     key: K,
     value: V,
     children: Vec<Self>,
 }
 
-impl<K, V> BKNode<K, V> {
+impl<K, V, D> BKNode<K, V, D> {
     fn len(&self) -> usize {
         self.children.iter().map(Self::len).sum::<usize>() + 1
     }
@@ -116,21 +241,31 @@ impl<K, V> BKNode<K, V> {
         }
     }
 
-    fn children_around(&self, dist: usize, radius: usize) -> impl Iterator<Item = &Self> {
+    fn into_entries(self, out: &mut Vec<(K, V)>) {
+        out.push((self.key, self.value));
+
+        for child in self.children {
+            child.into_entries(out);
+        }
+    }
+}
+
+impl<K, V, D: Dist> BKNode<K, V, D> {
+    fn children_around(&self, dist: D, radius: D) -> impl Iterator<Item = &Self> {
         self.children
             .iter()
-            .skip_while(move |child| child.dist.get() < dist.saturating_sub(radius))
-            .take_while(move |child| child.dist.get() <= dist.saturating_add(radius))
+            .skip_while(move |child| child.dist < dist.saturating_sub(radius))
+            .take_while(move |child| child.dist <= dist.saturating_add(radius))
     }
 }
 
-impl<K, V, M: BuildMetric + Default> Default for BKMap<K, V, M> {
+impl<K, V, M: BuildMetric + Default, D> Default for BKMap<K, V, M, D> {
     fn default() -> Self {
         Self::with_metric(M::default())
     }
 }
 
-impl<K, V, M: BuildMetric> BKMap<K, V, M> {
+impl<K, V, M: BuildMetric, D> BKMap<K, V, M, D> {
     #[must_use]
     pub fn with_metric(build_metric: M) -> Self {
         let metric = build_metric.build();
@@ -141,16 +276,51 @@ impl<K, V, M: BuildMetric> BKMap<K, V, M> {
         }
     }
 
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.root.as_ref().map_or(0, BKNode::len)
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.root.as_ref().map_or(0, BKNode::capacity)
+    }
+
+    pub fn shrink_to_fit(&mut self) {
+        if let Some(root) = &mut self.root {
+            root.shrink_to_fit();
+        }
+    }
+
+    pub fn iter(&self) -> BKIter<'_, K, V, D> {
+        BKIter {
+            stack: self.root.as_ref().into_iter().collect(),
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> BKIterMut<'_, K, V, D> {
+        BKIterMut {
+            stack: self.root.as_mut().into_iter().collect(),
+        }
+    }
+}
+
+impl<K, V, M: BuildMetric, D: Dist> BKMap<K, V, M, D> {
     pub fn insert<'a>(&'a mut self, key: K, value: V)
     where
-        M::Metric: for<'b> Metric<&'b K, &'a K>,
+        M::Metric: for<'b> Metric<&'b K, &'a K, Dist = D>,
     {
         self.insert_or_modify(key, value, |old, new| *old = new);
     }
 
     pub fn insert_or_modify<'a>(&'a mut self, key: K, value: V, modify: impl FnOnce(&mut V, V))
     where
-        M::Metric: for<'b> Metric<&'b K, &'a K>,
+        M::Metric: for<'b> Metric<&'b K, &'a K, Dist = D>,
     {
         self.insert_and_modify(key, value, |old, new| {
             if let Some(new) = new {
@@ -165,12 +335,12 @@ impl<K, V, M: BuildMetric> BKMap<K, V, M> {
         mut value: V,
         modify: impl FnOnce(&mut V, Option<V>),
     ) where
-        M::Metric: for<'b> Metric<&'b K, &'a K>,
+        M::Metric: for<'b> Metric<&'b K, &'a K, Dist = D>,
     {
         if self.root.is_none() {
             modify(&mut value, None);
             return self.root = Some(BKNode {
-                dist: NonZero::new(1).unwrap(),
+                dist: D::default(),
                 key,
                 value,
                 children: Vec::new(),
@@ -180,9 +350,11 @@ impl<K, V, M: BuildMetric> BKMap<K, V, M> {
         let mut node = self.root.as_mut().unwrap();
 
         loop {
-            let Some(dist) = NonZero::new(self.metric.distance(&key, &node.key)) else {
+            let dist = self.metric.distance(&key, &node.key);
+
+            if dist == D::default() {
                 return modify(&mut node.value, Some(value));
-            };
+            }
 
             let child = node.children.iter().position(|child| child.dist >= dist);
 
@@ -204,33 +376,131 @@ impl<K, V, M: BuildMetric> BKMap<K, V, M> {
     }
 
     #[must_use]
-    pub fn len(&self) -> usize {
-        self.root.as_ref().map_or(0, BKNode::len)
+    pub fn get<'a>(&'a self, key: &K) -> Option<&'a V>
+    where
+        M::Metric: for<'b> Metric<&'b K, &'a K, Dist = D>,
+    {
+        let mut metric = self.build_metric.build();
+        let mut node = self.root.as_ref()?;
+
+        loop {
+            let dist = metric.distance(key, &node.key);
+
+            if dist == D::default() {
+                return Some(&node.value);
+            }
+
+            node = node.children.iter().find(|child| child.dist == dist)?;
+        }
     }
 
     #[must_use]
-    pub fn is_empty(&self) -> bool {
-        self.root.is_none()
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V>
+    where
+        M::Metric: for<'b, 'c> Metric<&'b K, &'c K, Dist = D>,
+    {
+        let mut dist = self.metric.distance(key, &self.root.as_ref()?.key);
+
+        if dist == D::default() {
+            return Some(&mut self.root.as_mut().unwrap().value);
+        }
+
+        let mut children = &mut self.root.as_mut().unwrap().children;
+
+        loop {
+            let index = children.iter().position(|child| child.dist == dist)?;
+            dist = self.metric.distance(key, &children[index].key);
+
+            if dist == D::default() {
+                return Some(&mut children[index].value);
+            }
+
+            children = &mut children[index].children;
+        }
     }
 
     #[must_use]
-    pub fn capacity(&self) -> usize {
-        self.root.as_ref().map_or(0, BKNode::capacity)
+    pub fn contains_key<'a>(&'a self, key: &K) -> bool
+    where
+        M::Metric: for<'b> Metric<&'b K, &'a K, Dist = D>,
+    {
+        self.get(key).is_some()
     }
 
-    pub fn shrink_to_fit(&mut self) {
-        if let Some(root) = &mut self.root {
-            root.shrink_to_fit();
+    pub fn remove(&mut self, key: &K) -> Option<V>
+    where
+        M::Metric: for<'b, 'c> Metric<&'b K, &'c K, Dist = D>,
+    {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    pub fn remove_entry(&mut self, key: &K) -> Option<(K, V)>
+    where
+        M::Metric: for<'b, 'c> Metric<&'b K, &'c K, Dist = D>,
+    {
+        let node = self.detach(key)?;
+
+        let mut orphans = Vec::new();
+        for child in node.children {
+            child.into_entries(&mut orphans);
+        }
+
+        for (key, value) in orphans {
+            self.insert(key, value);
+        }
+
+        Some((node.key, node.value))
+    }
+
+    fn detach(&mut self, key: &K) -> Option<BKNode<K, V, D>>
+    where
+        M::Metric: for<'b, 'c> Metric<&'b K, &'c K, Dist = D>,
+    {
+        let mut dist = self.metric.distance(key, &self.root.as_ref()?.key);
+
+        if dist == D::default() {
+            return self.root.take();
+        }
+
+        let mut children = &mut self.root.as_mut().unwrap().children;
+
+        loop {
+            let index = children.iter().position(|child| child.dist == dist)?;
+            dist = self.metric.distance(key, &children[index].key);
+
+            if dist == D::default() {
+                return Some(children.remove(index));
+            }
+
+            children = &mut children[index].children;
+        }
+    }
+
+    pub fn retain(&mut self, mut f: impl FnMut(&K, &mut V) -> bool)
+    where
+        M::Metric: for<'b, 'c> Metric<&'b K, &'c K, Dist = D>,
+    {
+        let Some(root) = self.root.take() else {
+            return;
+        };
+
+        let mut entries = Vec::new();
+        root.into_entries(&mut entries);
+
+        for (key, mut value) in entries {
+            if f(&key, &mut value) {
+                self.insert(key, value);
+            }
         }
     }
 
     pub fn fuzzy_search_distance<'a, S>(
         &'a self,
         key: S,
-        distance: usize,
-    ) -> BKFuzzy<'a, K, V, M::Metric, S>
+        distance: D,
+    ) -> BKFuzzy<'a, K, V, M::Metric, S, D>
     where
-        M::Metric: for<'b> Metric<&'b S, &'a K>,
+        M::Metric: for<'b> Metric<&'b S, &'a K, Dist = D>,
     {
         BKFuzzy {
             metric: self.build_metric.build(),
@@ -239,19 +509,209 @@ impl<K, V, M: BuildMetric> BKMap<K, V, M> {
             distance,
         }
     }
+
+    /// Returns the `k` entries whose keys are closest to `key`, sorted by ascending distance.
+    #[must_use]
+    pub fn fuzzy_search_nearest<'a, S>(&'a self, key: S, k: usize) -> Vec<(D, &'a K, &'a V)>
+    where
+        M::Metric: for<'b> Metric<&'b S, &'a K, Dist = D>,
+    {
+        if k == 0 {
+            return Vec::new();
+        }
+
+        let mut metric = self.build_metric.build();
+        let mut stack: Vec<&BKNode<K, V, D>> = self.root.as_ref().into_iter().collect();
+        let mut heap = BinaryHeap::new();
+        let mut radius = None;
+
+        while let Some(node) = stack.pop() {
+            let dist = metric.distance(&key, &node.key);
+
+            if radius.is_none_or(|radius| dist <= radius) {
+                heap.push(NearestEntry {
+                    dist,
+                    key: &node.key,
+                    value: &node.value,
+                });
+
+                if heap.len() > k {
+                    heap.pop();
+                }
+
+                radius = (heap.len() >= k).then(|| heap.peek().unwrap().dist);
+            }
+
+            match radius {
+                Some(radius) => stack.extend(node.children_around(dist, radius)),
+                None => stack.extend(&node.children),
+            }
+        }
+
+        heap.into_sorted_vec()
+            .into_iter()
+            .map(|entry| (entry.dist, entry.key, entry.value))
+            .collect()
+    }
+}
+
+impl<K, V, M: BuildMetric, D> IntoIterator for BKMap<K, V, M, D> {
+    type Item = (K, V);
+    type IntoIter = BKIntoIter<K, V, D>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        BKIntoIter {
+            stack: self.root.into_iter().collect(),
+        }
+    }
+}
+
+impl<'a, K, V, M: BuildMetric, D> IntoIterator for &'a BKMap<K, V, M, D> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = BKIter<'a, K, V, D>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V, M: BuildMetric, D> IntoIterator for &'a mut BKMap<K, V, M, D> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = BKIterMut<'a, K, V, D>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<K, V, M: BuildMetric + Default, D: Dist> FromIterator<(K, V)> for BKMap<K, V, M, D>
+where
+    M::Metric: for<'a, 'b> Metric<&'a K, &'b K, Dist = D>,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::default();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K, V, M: BuildMetric, D: Dist> Extend<(K, V)> for BKMap<K, V, M, D>
+where
+    M::Metric: for<'a, 'b> Metric<&'a K, &'b K, Dist = D>,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
 }
 
 #[must_use = "iterators are lazy and do nothing unless consumed"]
 #[derive(Clone, Debug)]
-pub struct BKFuzzy<'a, K, V, M, S> {
+pub struct BKIter<'a, K, V, D> {
+    stack: Vec<&'a BKNode<K, V, D>>,
+}
+
+impl<'a, K, V, D> Iterator for BKIter<'a, K, V, D> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.stack.extend(&node.children);
+        Some((&node.key, &node.value))
+    }
+}
+
+impl<K, V, D> FusedIterator for BKIter<'_, K, V, D> {}
+
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[derive(Debug)]
+pub struct BKIterMut<'a, K, V, D> {
+    stack: Vec<&'a mut BKNode<K, V, D>>,
+}
+
+impl<'a, K, V, D> Iterator for BKIterMut<'a, K, V, D> {
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let BKNode {
+            key,
+            value,
+            children,
+            ..
+        } = self.stack.pop()?;
+
+        self.stack.extend(children.iter_mut());
+        Some((&*key, value))
+    }
+}
+
+impl<K, V, D> FusedIterator for BKIterMut<'_, K, V, D> {}
+
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct BKIntoIter<K, V, D> {
+    stack: Vec<BKNode<K, V, D>>,
+}
+
+impl<K, V, D> Iterator for BKIntoIter<K, V, D> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let BKNode {
+            key,
+            value,
+            children,
+            ..
+        } = self.stack.pop()?;
+
+        self.stack.extend(children);
+        Some((key, value))
+    }
+}
+
+impl<K, V, D> FusedIterator for BKIntoIter<K, V, D> {}
+
+struct NearestEntry<'a, K, V, D> {
+    dist: D,
+    key: &'a K,
+    value: &'a V,
+}
+
+impl<K, V, D: PartialEq> PartialEq for NearestEntry<'_, K, V, D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.dist == other.dist
+    }
+}
+
+impl<K, V, D: Eq> Eq for NearestEntry<'_, K, V, D> {}
+
+impl<K, V, D: Ord> PartialOrd for NearestEntry<'_, K, V, D> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K, V, D: Ord> Ord for NearestEntry<'_, K, V, D> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.dist.cmp(&other.dist)
+    }
+}
+
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct BKFuzzy<'a, K, V, M, S, D> {
     metric: M,
-    stack: Vec<&'a BKNode<K, V>>,
+    stack: Vec<&'a BKNode<K, V, D>>,
     key: S,
-    distance: usize,
+    distance: D,
 }
 
-impl<'a, K, V, M: for<'b> Metric<&'b S, &'a K>, S> Iterator for BKFuzzy<'a, K, V, M, S> {
-    type Item = (usize, &'a K, &'a V);
+impl<'a, K, V, M, S, D: Dist> Iterator for BKFuzzy<'a, K, V, M, S, D>
+where
+    M: for<'b> Metric<&'b S, &'a K, Dist = D>,
+{
+    type Item = (D, &'a K, &'a V);
 
     fn next(&mut self) -> Option<Self::Item> {
         loop {
@@ -267,4 +727,105 @@ impl<'a, K, V, M: for<'b> Metric<&'b S, &'a K>, S> Iterator for BKFuzzy<'a, K, V
     }
 }
 
-impl<K, V, M, S> FusedIterator for BKFuzzy<'_, K, V, M, S> where Self: Iterator {}
+impl<K, V, M, S, D> FusedIterator for BKFuzzy<'_, K, V, M, S, D> where Self: Iterator {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::String;
+
+    // Each word differs from every other only in its last byte, so every
+    // pairwise Levenshtein distance is 1. Inserting them in this order
+    // therefore builds a single chain root -> "aaab" -> "aaac" -> "aaad" ->
+    // "aaae", giving us a predictable root, leaf, and deep interior node.
+    const WORDS: [&str; 5] = ["aaaa", "aaab", "aaac", "aaad", "aaae"];
+
+    fn build() -> BKMap<String, i32, Levenshtein<u8>> {
+        let mut map = BKMap::default();
+
+        for (i, word) in WORDS.iter().enumerate() {
+            map.insert(String::from(*word), i as i32);
+        }
+
+        map
+    }
+
+    fn assert_searchable_except(map: &BKMap<String, i32, Levenshtein<u8>>, removed: &str) {
+        for word in WORDS {
+            assert_eq!(!map.contains_key(&String::from(word)), word == removed);
+        }
+    }
+
+    #[test]
+    fn remove_root() {
+        let mut map = build();
+
+        assert_eq!(map.remove(&String::from("aaaa")), Some(0));
+        assert_eq!(map.len(), 4);
+        assert_searchable_except(&map, "aaaa");
+    }
+
+    #[test]
+    fn remove_leaf() {
+        let mut map = build();
+
+        assert_eq!(map.remove(&String::from("aaae")), Some(4));
+        assert_eq!(map.len(), 4);
+        assert_searchable_except(&map, "aaae");
+    }
+
+    #[test]
+    fn remove_interior_with_deep_children() {
+        let mut map = build();
+
+        assert_eq!(map.remove(&String::from("aaab")), Some(1));
+        assert_eq!(map.len(), 4);
+        assert_searchable_except(&map, "aaab");
+    }
+
+    #[test]
+    fn collect_iterate_and_retain() {
+        let map: BKMap<String, i32, Levenshtein<u8>> = WORDS
+            .iter()
+            .enumerate()
+            .map(|(i, word)| (String::from(*word), i as i32))
+            .collect();
+
+        assert_eq!(map.len(), WORDS.len());
+        assert_eq!(
+            map.iter().map(|(_, value)| value).sum::<i32>(),
+            (0..WORDS.len() as i32).sum()
+        );
+
+        let mut map = map;
+        for (_, value) in map.iter_mut() {
+            *value *= 2;
+        }
+
+        map.retain(|_, value| *value >= 4);
+
+        assert_eq!(map.len(), 3);
+        for word in WORDS {
+            let removed = word == "aaaa" || word == "aaab";
+            assert_eq!(!map.contains_key(&String::from(word)), removed);
+        }
+    }
+
+    #[test]
+    fn damerau_levenshtein_transposition() {
+        let mut metric = DamerauLevenshtein::<u8>::default().build();
+
+        assert_eq!(metric.distance("teh", "the"), 1);
+        assert_eq!(metric.distance("the", "the"), 0);
+    }
+
+    #[test]
+    fn damerau_levenshtein_distinguishes_from_osa() {
+        let mut metric = DamerauLevenshtein::<u8>::default().build();
+
+        // OSA forbids reusing a substring across two edits, so it scores "ca"
+        // -> "abc" as 3 (substitute twice, insert once); the unrestricted
+        // variant finds the cheaper transpose-then-insert path.
+        assert_eq!(metric.distance("ca", "abc"), 2);
+    }
+}